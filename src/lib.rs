@@ -1,4 +1,7 @@
+mod session;
+
 use rand::prelude::*;
+use requestty::Question;
 use serde::{Deserialize, Serialize};
 use std::{
     fmt,
@@ -6,6 +9,8 @@ use std::{
     process::{ExitCode, Termination},
 };
 
+pub use session::play;
+
 pub enum ToolResult<T> {
     Ok(T),
     Err(ToolError),
@@ -26,6 +31,9 @@ impl<T> Termination for ToolResult<T> {
 #[derive(Debug)]
 pub enum ToolError {
     Other(String),
+    LegacyConversion(String),
+    Io(std::io::Error),
+    Parse { path: PathBuf, source: String },
 }
 
 impl std::error::Error for ToolError {}
@@ -34,36 +42,77 @@ impl fmt::Display for ToolError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Other(e) => write!(f, "Some other error: {}", e),
+            Self::LegacyConversion(reason) => {
+                write!(f, "Could not convert legacy game: {}", reason)
+            }
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+            Self::Parse { path, source } => {
+                write!(f, "failed to parse {}: {}", path.display(), source)
+            }
         }
     }
 }
 
+/// The schema version written into every `JeopardyGame` produced by this version of the tool.
+const CURRENT_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Debug, Serialize, Deserialize)]
 struct JeopardyGame {
     categories: Vec<JeopardyCategory>,
+    final_jeopardy: FinalJeopardy,
+    schema_version: u32,
 }
 
-impl JeopardyGame {
-    pub fn new() -> Self {
-        JeopardyGame {
-            categories: Vec::new(),
-        }
-    }
+/// The single clue played at the end of the game, once the board is cleared: every player
+/// wagers in secret, then answers `final_jeopardy.clue` knowing only its category.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct FinalJeopardy {
+    category: String,
+    clue: JeopardyAnswer,
 }
 
 impl JeopardyGame {
-    /// Check whether a game has 5 categories and every category has 5 answers
-    fn is_valid(&self) -> bool {
-        self.categories.len() == 5 && self.categories.iter().all(|c| c.is_valid())
+    /// Check whether a game has 5 categories, every category has 5 answers, and every answer
+    /// is itself sound, collecting every problem found rather than stopping at the first.
+    fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if self.categories.len() != 5 {
+            issues.push(ValidationIssue::WrongCategoryCount {
+                found: self.categories.len(),
+                expected: 5,
+            });
+        }
+
+        let mut seen_names = std::collections::HashSet::new();
+        for category in &self.categories {
+            if !seen_names.insert(category.name.clone()) {
+                issues.push(ValidationIssue::DuplicateCategoryName(category.name.clone()));
+            }
+            issues.extend(category.validate());
+        }
+
+        if self.final_jeopardy.category.trim().is_empty() {
+            issues.push(ValidationIssue::EmptyFinalJeopardyCategory);
+        }
+        issues.extend(
+            self.final_jeopardy
+                .clue
+                .validate(&self.final_jeopardy.category),
+        );
+        issues
     }
 
-    /// Randomly choose two answers to be a Double Jeopardy
-    fn double_jeopardy(&mut self) {
-        let mut rng = rand::thread_rng();
-        let mut indices = (0..self.categories.len()).collect::<Vec<_>>();
-        indices.shuffle(&mut rng);
-        for i in indices.iter().take(2) {
-            self.categories[*i].double_jeopardy();
+    /// Place Daily Doubles across the whole board according to `strategy`, clearing any
+    /// previous placement first.
+    fn place_daily_doubles(&mut self, strategy: &DailyDoubleStrategy, rng: &mut impl Rng) {
+        let cells = strategy.choose_cells(self, rng);
+        for category in &mut self.categories {
+            category.clear_daily_doubles();
+        }
+        for (cat_idx, ans_idx) in cells {
+            if let Some(category) = self.categories.get_mut(cat_idx) {
+                category.mark_daily_double(ans_idx);
+            }
         }
     }
 }
@@ -75,39 +124,33 @@ struct JeopardyCategory {
 }
 
 impl JeopardyCategory {
-    /// Check whether a category has 5 answers
-    fn is_valid(&self) -> bool {
-        self.answers.len() == 5 && self.answers.iter().all(|a| a.is_valid())
-    }
-
-    /// Randomly choose two answers to be a Double Jeopardy
-    fn double_jeopardy(&mut self) {
-        let mut rng = rand::thread_rng();
-        let mut indices = (0..self.answers.len()).collect::<Vec<_>>();
-        indices.shuffle(&mut rng);
-        for i in indices.iter().take(2) {
-            match &mut self.answers[*i] {
-                JeopardyAnswer::Text {
-                    answer: _,
-                    question: _,
-                    double_jeopardy,
-                } => *double_jeopardy = true,
-                JeopardyAnswer::Image {
-                    question: _,
-                    image: _,
-                    double_jeopardy,
-                } => *double_jeopardy = true,
-                JeopardyAnswer::Audio {
-                    question: _,
-                    audio: _,
-                    double_jeopardy,
-                } => *double_jeopardy = true,
-                JeopardyAnswer::Video {
-                    question: _,
-                    video: _,
-                    double_jeopardy,
-                } => *double_jeopardy = true,
-            };
+    /// Check whether a category has 5 answers and every answer is itself sound
+    fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if self.answers.len() != 5 {
+            issues.push(ValidationIssue::WrongAnswerCount {
+                category: self.name.clone(),
+                found: self.answers.len(),
+                expected: 5,
+            });
+        }
+        for answer in &self.answers {
+            issues.extend(answer.validate(&self.name));
+        }
+        issues
+    }
+
+    /// Clear the Daily Double flag on every answer in this category.
+    fn clear_daily_doubles(&mut self) {
+        for answer in &mut self.answers {
+            answer.set_double_jeopardy(false);
+        }
+    }
+
+    /// Flag the answer at `idx` as a Daily Double, if it exists.
+    fn mark_daily_double(&mut self, idx: usize) {
+        if let Some(answer) = self.answers.get_mut(idx) {
+            answer.set_double_jeopardy(true);
         }
     }
 }
@@ -136,37 +179,737 @@ enum JeopardyAnswer {
     },
 }
 
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif"];
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "wav", "ogg", "flac"];
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "mov", "webm", "mkv"];
+
 impl JeopardyAnswer {
-    /// Check whether an answer is valid
-    fn is_valid(&self) -> bool {
+    /// Check whether an answer's question/answer text is non-empty and, for media variants,
+    /// that the referenced file exists on disk with an expected extension.
+    fn validate(&self, category: &str) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if question_of(self).trim().is_empty() {
+            issues.push(ValidationIssue::EmptyQuestion {
+                category: category.to_string(),
+            });
+        }
+
         match self {
-            Self::Text { .. } => true,
-            Self::Image { .. } => true,
-            Self::Audio { .. } => true,
-            Self::Video { .. } => true,
+            Self::Text { answer, .. } => {
+                if answer.trim().is_empty() {
+                    issues.push(ValidationIssue::EmptyAnswer {
+                        category: category.to_string(),
+                    });
+                }
+            }
+            Self::Image { image, .. } => {
+                issues.extend(validate_media_path(category, image, IMAGE_EXTENSIONS));
+            }
+            Self::Audio { audio, .. } => {
+                issues.extend(validate_media_path(category, audio, AUDIO_EXTENSIONS));
+            }
+            Self::Video { video, .. } => {
+                issues.extend(validate_media_path(category, video, VIDEO_EXTENSIONS));
+            }
+        }
+        issues
+    }
+
+    fn is_double_jeopardy(&self) -> bool {
+        match self {
+            Self::Text { double_jeopardy, .. }
+            | Self::Image { double_jeopardy, .. }
+            | Self::Audio { double_jeopardy, .. }
+            | Self::Video { double_jeopardy, .. } => *double_jeopardy,
+        }
+    }
+
+    fn set_double_jeopardy(&mut self, value: bool) {
+        match self {
+            Self::Text { double_jeopardy, .. }
+            | Self::Image { double_jeopardy, .. }
+            | Self::Audio { double_jeopardy, .. }
+            | Self::Video { double_jeopardy, .. } => *double_jeopardy = value,
         }
     }
 }
 
-pub fn show(prefix: Option<String>) -> ToolResult<()> {
-    println!("{:?}", prefix);
-    let mut jeopardy_game = JeopardyGame::new();
-    println!("{:?}", jeopardy_game);
-    println!("{:?}", jeopardy_game.is_valid());
-    jeopardy_game.double_jeopardy();
-    ToolResult::Err(ToolError::Other("Some error".to_string()))
+/// Which `DailyDoubleStrategy` to build, as selected on the command line. Unlike
+/// `DailyDoubleStrategy` itself, every variant here is a plain tag with no payload, so it can be
+/// parsed directly from a CLI flag; `create` turns the chosen tag plus any accompanying flags
+/// (bias, fixed cells) into the real strategy.
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum DailyDoubleStrategyArg {
+    /// Place Daily Doubles uniformly at random across the whole board.
+    Classic,
+    /// Place Daily Doubles with a bias toward higher-value rows.
+    WeightedByRow,
+    /// Place Daily Doubles at caller-supplied cells.
+    Fixed,
+}
+
+/// How Daily Double cells are chosen across the whole board. Takes an injectable `Rng` so
+/// placement is seedable and reproducible in tests.
+#[derive(Debug, Clone)]
+enum DailyDoubleStrategy {
+    /// Pick `count` cells uniformly at random from the whole board.
+    Classic { count: usize },
+    /// Pick `STANDARD_DAILY_DOUBLE_COUNT` cells, weighting each cell's selection probability
+    /// by its row's point value raised to `bias` (higher bias favors higher-value rows more
+    /// strongly; a bias of zero is equivalent to `Classic`).
+    WeightedByRow { bias: f64 },
+    /// Place Daily Doubles at exactly these `(category, answer)` cells.
+    FixedCells(Vec<(usize, usize)>),
+}
+
+/// The number of Daily Doubles a standard board carries, used by strategies that don't take
+/// an explicit count.
+const STANDARD_DAILY_DOUBLE_COUNT: usize = 2;
+
+impl DailyDoubleStrategy {
+    /// Choose which `(category, answer)` cells get a Daily Double for `game`.
+    fn choose_cells(&self, game: &JeopardyGame, rng: &mut impl Rng) -> Vec<(usize, usize)> {
+        let all_cells: Vec<(usize, usize)> = game
+            .categories
+            .iter()
+            .enumerate()
+            .flat_map(|(cat_idx, category)| {
+                (0..category.answers.len()).map(move |ans_idx| (cat_idx, ans_idx))
+            })
+            .collect();
+
+        match self {
+            Self::Classic { count } => {
+                let mut cells = all_cells;
+                cells.shuffle(rng);
+                cells.truncate(*count);
+                cells
+            }
+            Self::WeightedByRow { bias } => {
+                sample_weighted_by_row(&all_cells, STANDARD_DAILY_DOUBLE_COUNT, *bias, rng)
+            }
+            Self::FixedCells(cells) => cells.clone(),
+        }
+    }
+}
+
+/// Sample `count` cells without replacement, weighting each draw by its row's point value
+/// raised to `bias`.
+fn sample_weighted_by_row(
+    cells: &[(usize, usize)],
+    count: usize,
+    bias: f64,
+    rng: &mut impl Rng,
+) -> Vec<(usize, usize)> {
+    let mut remaining = cells.to_vec();
+    let mut chosen = Vec::with_capacity(count.min(remaining.len()));
+
+    for _ in 0..count.min(remaining.len()) {
+        let weights: Vec<f64> = remaining.iter().map(|(_, row)| row_weight(*row, bias)).collect();
+        let total: f64 = weights.iter().sum();
+        let mut threshold = rng.gen::<f64>() * total;
+
+        let mut pick = remaining.len() - 1;
+        for (i, weight) in weights.iter().enumerate() {
+            if threshold < *weight {
+                pick = i;
+                break;
+            }
+            threshold -= weight;
+        }
+        chosen.push(remaining.remove(pick));
+    }
+    chosen
+}
+
+fn row_weight(row: usize, bias: f64) -> f64 {
+    let value = session::ROW_VALUES
+        .get(row)
+        .copied()
+        .unwrap_or(*session::ROW_VALUES.last().unwrap()) as f64;
+    value.powf(bias)
+}
+
+fn question_of(answer: &JeopardyAnswer) -> &str {
+    match answer {
+        JeopardyAnswer::Text { question, .. }
+        | JeopardyAnswer::Image { question, .. }
+        | JeopardyAnswer::Audio { question, .. }
+        | JeopardyAnswer::Video { question, .. } => question,
+    }
+}
+
+fn validate_media_path(
+    category: &str,
+    path: &std::path::Path,
+    expected_extensions: &[&str],
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if !path.exists() {
+        issues.push(ValidationIssue::MissingMediaFile {
+            category: category.to_string(),
+            path: path.to_path_buf(),
+        });
+    }
+    let has_expected_extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| expected_extensions.contains(&ext))
+        .unwrap_or(false);
+    if !has_expected_extension {
+        issues.push(ValidationIssue::UnexpectedMediaExtension {
+            category: category.to_string(),
+            path: path.to_path_buf(),
+        });
+    }
+    issues
+}
+
+/// A single problem found while validating a `JeopardyGame`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ValidationIssue {
+    WrongCategoryCount {
+        found: usize,
+        expected: usize,
+    },
+    DuplicateCategoryName(String),
+    EmptyFinalJeopardyCategory,
+    WrongAnswerCount {
+        category: String,
+        found: usize,
+        expected: usize,
+    },
+    EmptyQuestion {
+        category: String,
+    },
+    EmptyAnswer {
+        category: String,
+    },
+    MissingMediaFile {
+        category: String,
+        path: PathBuf,
+    },
+    UnexpectedMediaExtension {
+        category: String,
+        path: PathBuf,
+    },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::WrongCategoryCount { found, expected } => {
+                write!(f, "expected {} categories, found {}", expected, found)
+            }
+            Self::DuplicateCategoryName(name) => write!(f, "duplicate category name: {}", name),
+            Self::EmptyFinalJeopardyCategory => {
+                write!(f, "Final Jeopardy has an empty category name")
+            }
+            Self::WrongAnswerCount {
+                category,
+                found,
+                expected,
+            } => write!(
+                f,
+                "category \"{}\" has {} answers, expected {}",
+                category, found, expected
+            ),
+            Self::EmptyQuestion { category } => write!(
+                f,
+                "category \"{}\" has an answer with an empty question",
+                category
+            ),
+            Self::EmptyAnswer { category } => write!(
+                f,
+                "category \"{}\" has an answer with empty answer text",
+                category
+            ),
+            Self::MissingMediaFile { category, path } => write!(
+                f,
+                "category \"{}\" references a media file that does not exist: {}",
+                category,
+                path.display()
+            ),
+            Self::UnexpectedMediaExtension { category, path } => write!(
+                f,
+                "category \"{}\" references a media file with an unexpected extension: {}",
+                category,
+                path.display()
+            ),
+        }
+    }
+}
+
+/// Scan `dir` for game files whose stem begins with `prefix` and print a summary of each.
+pub fn show(prefix: Option<String>, dir: PathBuf) -> ToolResult<()> {
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) => return ToolResult::Err(ToolError::Io(e)),
+    };
+
+    let mut found_any = false;
+    for entry in entries {
+        let path = match entry {
+            Ok(entry) => entry.path(),
+            Err(e) => {
+                eprintln!("{}", ToolError::Io(e));
+                continue;
+            }
+        };
+        if !path.is_file() {
+            continue;
+        }
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        if !stem.starts_with(prefix.as_deref().unwrap_or("")) {
+            continue;
+        }
+        found_any = true;
+
+        match load_game(&path) {
+            Ok(game) => print_game_summary(&path, &game),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    if !found_any {
+        println!("No games found matching the given prefix in {}", dir.display());
+    }
+
+    ToolResult::Ok(())
+}
+
+/// Deserialize a game file, dispatching on its extension.
+fn load_game(path: &std::path::Path) -> Result<JeopardyGame, ToolError> {
+    let contents = std::fs::read_to_string(path).map_err(ToolError::Io)?;
+    parse_game(path.extension().and_then(|e| e.to_str()), &contents).map_err(|source| {
+        ToolError::Parse {
+            path: path.to_path_buf(),
+            source,
+        }
+    })
+}
+
+/// Deserialize a game's contents according to the format implied by `extension`.
+fn parse_game(extension: Option<&str>, contents: &str) -> Result<JeopardyGame, String> {
+    match extension {
+        Some("json") => serde_json::from_str(contents).map_err(|e| e.to_string()),
+        Some("toml") => toml::from_str(contents).map_err(|e| e.to_string()),
+        Some("yaml") | Some("yml") => serde_yaml::from_str(contents).map_err(|e| e.to_string()),
+        other => Err(format!("unsupported file extension: {:?}", other)),
+    }
+}
+
+fn print_game_summary(path: &std::path::Path, game: &JeopardyGame) {
+    println!("{}:", path.display());
+    for category in &game.categories {
+        let double_jeopardy_count = category
+            .answers
+            .iter()
+            .filter(|a| a.is_double_jeopardy())
+            .count();
+        println!(
+            "  {} ({} answers, {} Double Jeopardy)",
+            category.name,
+            category.answers.len(),
+            double_jeopardy_count
+        );
+    }
+
+    for issue in game.validate() {
+        println!("  ! {}", issue);
+    }
+}
+
+/// Walk the user through building a new game interactively, then write it to `output`.
+///
+/// `strategy` selects how Daily Doubles are placed; `bias` is only used for
+/// `DailyDoubleStrategyArg::WeightedByRow` and `fixed_cells` (each formatted as
+/// `"category,answer"`) only for `DailyDoubleStrategyArg::Fixed`.
+pub fn create(
+    output: PathBuf,
+    strategy: DailyDoubleStrategyArg,
+    bias: f64,
+    fixed_cells: Vec<String>,
+) -> ToolResult<()> {
+    let strategy = match strategy {
+        DailyDoubleStrategyArg::Classic => DailyDoubleStrategy::Classic {
+            count: STANDARD_DAILY_DOUBLE_COUNT,
+        },
+        DailyDoubleStrategyArg::WeightedByRow => DailyDoubleStrategy::WeightedByRow { bias },
+        DailyDoubleStrategyArg::Fixed => match parse_fixed_cells(&fixed_cells) {
+            Ok(cells) => DailyDoubleStrategy::FixedCells(cells),
+            Err(e) => return ToolResult::Err(e),
+        },
+    };
+
+    let game = match build_game_interactively(&strategy) {
+        Ok(game) => game,
+        Err(e) => return ToolResult::Err(e),
+    };
+
+    let serialized = match serde_json::to_string_pretty(&game) {
+        Ok(serialized) => serialized,
+        Err(e) => return ToolResult::Err(ToolError::Other(e.to_string())),
+    };
+
+    match std::fs::write(&output, serialized) {
+        Ok(()) => ToolResult::Ok(()),
+        Err(e) => ToolResult::Err(ToolError::Other(e.to_string())),
+    }
+}
+
+/// Parse `"category,answer"` pairs into the `(category, answer)` cell indices
+/// `DailyDoubleStrategy::FixedCells` expects.
+fn parse_fixed_cells(raw: &[String]) -> Result<Vec<(usize, usize)>, ToolError> {
+    raw.iter()
+        .map(|pair| {
+            let (category, answer) = pair.split_once(',').ok_or_else(|| {
+                ToolError::Other(format!(
+                    "expected a \"category,answer\" pair, got {:?}",
+                    pair
+                ))
+            })?;
+            let category = category
+                .parse::<usize>()
+                .map_err(|e| ToolError::Other(e.to_string()))?;
+            let answer = answer
+                .parse::<usize>()
+                .map_err(|e| ToolError::Other(e.to_string()))?;
+            Ok((category, answer))
+        })
+        .collect()
+}
+
+/// Prompt for five categories and a Final Jeopardy clue, re-prompting from scratch whenever the
+/// result isn't valid.
+fn build_game_interactively(strategy: &DailyDoubleStrategy) -> Result<JeopardyGame, ToolError> {
+    loop {
+        let categories = (0..5)
+            .map(prompt_category)
+            .collect::<Result<Vec<_>, _>>()?;
+        let final_jeopardy = prompt_final_jeopardy()?;
+        let mut game = JeopardyGame {
+            categories,
+            final_jeopardy,
+            schema_version: CURRENT_SCHEMA_VERSION,
+        };
+        let issues = game.validate();
+        if issues.is_empty() {
+            game.place_daily_doubles(strategy, &mut rand::thread_rng());
+            return Ok(game);
+        }
+        println!("That game isn't valid yet, let's try again from the top:");
+        for issue in issues {
+            println!("  ! {}", issue);
+        }
+    }
+}
+
+fn prompt_category(index: usize) -> Result<JeopardyCategory, ToolError> {
+    let name = prompt_string(&format!("Name of category {}", index + 1))?;
+    let answers = (0..5)
+        .map(|_| prompt_answer())
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(JeopardyCategory { name, answers })
+}
+
+fn prompt_final_jeopardy() -> Result<FinalJeopardy, ToolError> {
+    let category = prompt_string("Final Jeopardy category")?;
+    let clue = prompt_answer()?;
+    Ok(FinalJeopardy { category, clue })
+}
+
+fn prompt_answer() -> Result<JeopardyAnswer, ToolError> {
+    let variant = requestty::prompt_one(
+        Question::select("variant")
+            .message("Answer type")
+            .choices(vec!["Text", "Image", "Audio", "Video"])
+            .build(),
+    )
+    .map_err(|e| ToolError::Other(e.to_string()))?;
+    let variant = variant
+        .as_list_item()
+        .ok_or_else(|| ToolError::Other("expected a selection".to_string()))?
+        .text
+        .clone();
+
+    let question = prompt_string("Question")?;
+
+    match variant.as_str() {
+        "Text" => {
+            let answer = prompt_string("Answer")?;
+            Ok(JeopardyAnswer::Text {
+                answer,
+                question,
+                double_jeopardy: false,
+            })
+        }
+        "Image" => Ok(JeopardyAnswer::Image {
+            question,
+            image: prompt_path("Path to the image file")?,
+            double_jeopardy: false,
+        }),
+        "Audio" => Ok(JeopardyAnswer::Audio {
+            question,
+            audio: prompt_path("Path to the audio file")?,
+            double_jeopardy: false,
+        }),
+        "Video" => Ok(JeopardyAnswer::Video {
+            question,
+            video: prompt_path("Path to the video file")?,
+            double_jeopardy: false,
+        }),
+        _ => unreachable!("the select above only offers the four known variants"),
+    }
+}
+
+fn prompt_string(message: &str) -> Result<String, ToolError> {
+    requestty::prompt_one(Question::input("value").message(message).build())
+        .map_err(|e| ToolError::Other(e.to_string()))?
+        .try_into_string()
+        .map_err(|_| ToolError::Other("expected a text answer".to_string()))
+}
+
+fn prompt_path(message: &str) -> Result<PathBuf, ToolError> {
+    prompt_string(message).map(PathBuf::from)
+}
+
+/// The pre-`schema_version` on-disk shape this tool used to read and write.
+#[derive(Debug, Serialize, Deserialize)]
+struct LegacyGame {
+    categories: Vec<LegacyCategory>,
+    final_jeopardy: LegacyFinalJeopardy,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LegacyFinalJeopardy {
+    category: String,
+    clue: LegacyClue,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LegacyCategory {
+    name: String,
+    clues: Vec<LegacyClue>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LegacyClue {
+    question: String,
+    /// Only set for plain text clues.
+    answer: Option<String>,
+    /// Set for image/audio/video clues; the variant is inferred from the extension.
+    media: Option<PathBuf>,
+}
+
+/// Read a legacy-format game from `input` and write today's format to `output`.
+pub fn convert(input: PathBuf, output: PathBuf) -> ToolResult<()> {
+    let contents = match std::fs::read_to_string(&input) {
+        Ok(contents) => contents,
+        Err(e) => return ToolResult::Err(ToolError::Io(e)),
+    };
+
+    let legacy: LegacyGame = match serde_json::from_str(&contents) {
+        Ok(legacy) => legacy,
+        Err(e) => {
+            return ToolResult::Err(ToolError::Parse {
+                path: input,
+                source: e.to_string(),
+            })
+        }
+    };
+
+    let game = match interpret_legacy_game(legacy) {
+        Ok(game) => game,
+        Err(e) => return ToolResult::Err(e),
+    };
+
+    let serialized = match serde_json::to_string_pretty(&game) {
+        Ok(serialized) => serialized,
+        Err(e) => return ToolResult::Err(ToolError::Other(e.to_string())),
+    };
+
+    match std::fs::write(&output, serialized) {
+        Ok(()) => ToolResult::Ok(()),
+        Err(e) => ToolResult::Err(ToolError::Io(e)),
+    }
+}
+
+/// Map a `LegacyGame` onto today's `JeopardyGame`, tagging it with the current schema version.
+fn interpret_legacy_game(legacy: LegacyGame) -> Result<JeopardyGame, ToolError> {
+    if legacy.categories.len() != 5 {
+        return Err(ToolError::LegacyConversion(format!(
+            "expected 5 categories, found {}",
+            legacy.categories.len()
+        )));
+    }
+
+    let categories = legacy
+        .categories
+        .into_iter()
+        .map(interpret_legacy_category)
+        .collect::<Result<Vec<_>, _>>()?;
+    let final_jeopardy = FinalJeopardy {
+        category: legacy.final_jeopardy.category,
+        clue: interpret_legacy_clue(legacy.final_jeopardy.clue)?,
+    };
+
+    Ok(JeopardyGame {
+        categories,
+        final_jeopardy,
+        schema_version: CURRENT_SCHEMA_VERSION,
+    })
+}
+
+fn interpret_legacy_category(legacy: LegacyCategory) -> Result<JeopardyCategory, ToolError> {
+    let LegacyCategory { name, clues } = legacy;
+    if clues.len() != 5 {
+        return Err(ToolError::LegacyConversion(format!(
+            "category \"{}\" has {} clues, expected 5",
+            name,
+            clues.len()
+        )));
+    }
+
+    let answers = clues
+        .into_iter()
+        .map(interpret_legacy_clue)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(JeopardyCategory { name, answers })
+}
+
+/// Infer the `JeopardyAnswer` variant from whether a clue carries a media path or plain text,
+/// defaulting `double_jeopardy` to `false` since the legacy format had no such concept.
+fn interpret_legacy_clue(clue: LegacyClue) -> Result<JeopardyAnswer, ToolError> {
+    if clue.question.trim().is_empty() {
+        return Err(ToolError::LegacyConversion(
+            "clue is missing a question".to_string(),
+        ));
+    }
+
+    if let Some(media) = clue.media {
+        match media.extension().and_then(|ext| ext.to_str()) {
+            Some("png") | Some("jpg") | Some("jpeg") | Some("gif") => Ok(JeopardyAnswer::Image {
+                question: clue.question,
+                image: media,
+                double_jeopardy: false,
+            }),
+            Some("mp3") | Some("wav") | Some("ogg") | Some("flac") => Ok(JeopardyAnswer::Audio {
+                question: clue.question,
+                audio: media,
+                double_jeopardy: false,
+            }),
+            Some("mp4") | Some("mov") | Some("webm") | Some("mkv") => Ok(JeopardyAnswer::Video {
+                question: clue.question,
+                video: media,
+                double_jeopardy: false,
+            }),
+            other => Err(ToolError::LegacyConversion(format!(
+                "unrecognized media extension: {:?}",
+                other
+            ))),
+        }
+    } else if let Some(answer) = clue.answer {
+        Ok(JeopardyAnswer::Text {
+            answer,
+            question: clue.question,
+            double_jeopardy: false,
+        })
+    } else {
+        Err(ToolError::LegacyConversion(
+            "clue has neither an answer nor a media path".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn sample_game() -> JeopardyGame {
+        let categories = (0..5)
+            .map(|cat| JeopardyCategory {
+                name: format!("Category {}", cat),
+                answers: (0..5)
+                    .map(|ans| JeopardyAnswer::Text {
+                        answer: format!("answer {}", ans),
+                        question: format!("question {}", ans),
+                        double_jeopardy: false,
+                    })
+                    .collect(),
+            })
+            .collect();
+        JeopardyGame {
+            categories,
+            final_jeopardy: FinalJeopardy {
+                category: "Final Category".to_string(),
+                clue: JeopardyAnswer::Text {
+                    answer: "final answer".to_string(),
+                    question: "final question".to_string(),
+                    double_jeopardy: false,
+                },
+            },
+            schema_version: CURRENT_SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn classic_strategy_places_exact_count_across_whole_board() {
+        let game = sample_game();
+        let mut rng = StdRng::seed_from_u64(42);
+        let cells = DailyDoubleStrategy::Classic { count: 2 }.choose_cells(&game, &mut rng);
+        assert_eq!(cells.len(), 2);
+        assert_eq!(
+            cells.iter().collect::<std::collections::HashSet<_>>().len(),
+            2,
+            "the two Daily Doubles should land on distinct cells, possibly in the same category"
+        );
+    }
+
+    #[test]
+    fn fixed_cells_strategy_returns_exactly_those_cells() {
+        let game = sample_game();
+        let mut rng = StdRng::seed_from_u64(1);
+        let cells = DailyDoubleStrategy::FixedCells(vec![(0, 1), (3, 4)]).choose_cells(&game, &mut rng);
+        assert_eq!(cells, vec![(0, 1), (3, 4)]);
+    }
+
+    #[test]
+    fn weighted_by_row_strategy_favors_high_value_rows() {
+        let game = sample_game();
+        let mut high_row_hits = 0;
+        for seed in 0..200 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let cells = DailyDoubleStrategy::WeightedByRow { bias: 4.0 }.choose_cells(&game, &mut rng);
+            if cells.iter().any(|(_, row)| *row == 4) {
+                high_row_hits += 1;
+            }
+        }
+        assert!(
+            high_row_hits > 100,
+            "expected the top row to be picked more often than not, got {} out of 200",
+            high_row_hits
+        );
+    }
 
     prop_compose! {
         fn jeopardy_game_strategy()(
             categories in prop::collection::vec(jeopardy_category_strategy(), 5),
+            final_jeopardy_category in any::<String>(),
+            final_jeopardy_clue in jeopardy_answer_strategy(),
+            schema_version in any::<u32>(),
         ) -> JeopardyGame {
-            JeopardyGame { categories }
+            JeopardyGame {
+                categories,
+                final_jeopardy: FinalJeopardy {
+                    category: final_jeopardy_category,
+                    clue: final_jeopardy_clue,
+                },
+                schema_version,
+            }
         }
     }
 
@@ -226,24 +969,171 @@ mod tests {
         ]
     }
 
+    prop_compose! {
+        fn non_empty_text_answer_strategy()(
+            answer in "\\PC*\\S\\PC*",
+            question in "\\PC*\\S\\PC*",
+            dj in any::<bool>(),
+        ) -> JeopardyAnswer {
+            JeopardyAnswer::Text {
+                answer,
+                question,
+                double_jeopardy: dj,
+            }
+        }
+    }
+
     proptest! {
         #[test]
-        fn jeopardy_game_is_valid(jeopardy_game in jeopardy_game_strategy()) {
-            assert!(jeopardy_game.is_valid());
+        fn jeopardy_game_always_has_right_category_count(jeopardy_game in jeopardy_game_strategy()) {
+            assert!(!jeopardy_game
+                .validate()
+                .iter()
+                .any(|issue| matches!(issue, ValidationIssue::WrongCategoryCount { .. })));
         }
     }
 
     proptest! {
         #[test]
-        fn jeopardy_category_is_valid(jeopardy_category in jeopardy_category_strategy()) {
-            assert!(jeopardy_category.is_valid());
+        fn jeopardy_category_always_has_right_answer_count(jeopardy_category in jeopardy_category_strategy()) {
+            assert!(!jeopardy_category
+                .validate()
+                .iter()
+                .any(|issue| matches!(issue, ValidationIssue::WrongAnswerCount { .. })));
         }
     }
 
     proptest! {
         #[test]
-        fn jeopardy_answer_is_valid(jeopardy_answer in jeopardy_answer_strategy()) {
-            assert!(jeopardy_answer.is_valid());
+        fn jeopardy_answer_with_text_has_no_empty_field_issues(
+            jeopardy_answer in non_empty_text_answer_strategy()
+        ) {
+            let issues = jeopardy_answer.validate("category");
+            assert!(!issues.iter().any(|issue| matches!(
+                issue,
+                ValidationIssue::EmptyQuestion { .. } | ValidationIssue::EmptyAnswer { .. }
+            )));
+        }
+    }
+
+    fn legacy_clue(answer: Option<&str>, media: Option<&str>) -> LegacyClue {
+        LegacyClue {
+            question: "question".to_string(),
+            answer: answer.map(str::to_string),
+            media: media.map(PathBuf::from),
+        }
+    }
+
+    fn legacy_category(name: &str, clues: Vec<LegacyClue>) -> LegacyCategory {
+        LegacyCategory {
+            name: name.to_string(),
+            clues,
         }
     }
+
+    fn legacy_game(categories: Vec<LegacyCategory>) -> LegacyGame {
+        LegacyGame {
+            categories,
+            final_jeopardy: LegacyFinalJeopardy {
+                category: "Final Category".to_string(),
+                clue: legacy_clue(Some("final answer"), None),
+            },
+        }
+    }
+
+    fn sample_legacy_category(name: &str) -> LegacyCategory {
+        legacy_category(
+            name,
+            (0..5)
+                .map(|i| legacy_clue(Some(&format!("answer {}", i)), None))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn interpret_legacy_game_rejects_wrong_category_count() {
+        let game = legacy_game(vec![sample_legacy_category("Only One")]);
+        let err = interpret_legacy_game(game).unwrap_err();
+        assert!(matches!(err, ToolError::LegacyConversion(_)));
+    }
+
+    #[test]
+    fn interpret_legacy_game_converts_a_well_formed_game() {
+        let categories = (0..5)
+            .map(|i| sample_legacy_category(&format!("Category {}", i)))
+            .collect();
+        let game = interpret_legacy_game(legacy_game(categories)).unwrap();
+        assert_eq!(game.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(game.categories.len(), 5);
+    }
+
+    #[test]
+    fn interpret_legacy_category_rejects_wrong_clue_count() {
+        let category = legacy_category(
+            "Too Few",
+            vec![legacy_clue(Some("answer"), None), legacy_clue(Some("answer"), None)],
+        );
+        let err = interpret_legacy_category(category).unwrap_err();
+        assert!(matches!(err, ToolError::LegacyConversion(_)));
+    }
+
+    #[test]
+    fn interpret_legacy_clue_rejects_unrecognized_media_extension() {
+        let clue = legacy_clue(None, Some("clip.avi"));
+        let err = interpret_legacy_clue(clue).unwrap_err();
+        assert!(matches!(err, ToolError::LegacyConversion(_)));
+    }
+
+    #[test]
+    fn interpret_legacy_clue_rejects_neither_answer_nor_media() {
+        let clue = legacy_clue(None, None);
+        let err = interpret_legacy_clue(clue).unwrap_err();
+        assert!(matches!(err, ToolError::LegacyConversion(_)));
+    }
+
+    #[test]
+    fn interpret_legacy_clue_infers_image_variant_from_extension() {
+        let clue = legacy_clue(None, Some("picture.png"));
+        let answer = interpret_legacy_clue(clue).unwrap();
+        assert!(matches!(answer, JeopardyAnswer::Image { .. }));
+    }
+
+    #[test]
+    fn interpret_legacy_clue_converts_plain_text() {
+        let clue = legacy_clue(Some("42"), None);
+        let answer = interpret_legacy_clue(clue).unwrap();
+        assert!(matches!(answer, JeopardyAnswer::Text { answer, .. } if answer == "42"));
+    }
+
+    #[test]
+    fn parse_game_reads_json() {
+        let contents = serde_json::to_string(&sample_game()).unwrap();
+        let game = parse_game(Some("json"), &contents).unwrap();
+        assert_eq!(game.categories.len(), 5);
+    }
+
+    #[test]
+    fn parse_game_reads_toml() {
+        let contents = toml::to_string(&sample_game()).unwrap();
+        let game = parse_game(Some("toml"), &contents).unwrap();
+        assert_eq!(game.categories.len(), 5);
+    }
+
+    #[test]
+    fn parse_game_reads_yaml_and_yml() {
+        let contents = serde_yaml::to_string(&sample_game()).unwrap();
+        assert!(parse_game(Some("yaml"), &contents).is_ok());
+        assert!(parse_game(Some("yml"), &contents).is_ok());
+    }
+
+    #[test]
+    fn parse_game_rejects_unsupported_extension() {
+        assert!(parse_game(Some("txt"), "").is_err());
+        assert!(parse_game(None, "").is_err());
+    }
+
+    #[test]
+    fn parse_game_rejects_malformed_content() {
+        assert!(parse_game(Some("json"), "not json").is_err());
+    }
 }
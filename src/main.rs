@@ -1,5 +1,6 @@
 use clap::Parser;
 use jeopardytool::ToolResult;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[clap(version, author, about)]
@@ -8,9 +9,11 @@ enum CLI {
     /// Show the available categories
     Show(Show),
     /// Create a new game
-    Create,
+    Create(Create),
     /// Convert a old category to the new format
-    Convert,
+    Convert(Convert),
+    /// Play through a game
+    Play(Play),
 }
 
 #[derive(Parser, Debug)]
@@ -18,13 +21,54 @@ struct Show {
     /// A prefix to select only a subset of games to use in analyzing
     #[clap(long, short)]
     prefix: Option<String>,
+    /// The directory to scan for game files
+    #[clap(long, short, default_value = "games")]
+    dir: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+struct Create {
+    /// Where to write the finished game file
+    #[clap(long, short)]
+    output: PathBuf,
+    /// How Daily Double cells are chosen
+    #[clap(long, value_enum, default_value = "classic")]
+    daily_double_strategy: jeopardytool::DailyDoubleStrategyArg,
+    /// Bias toward higher-value rows; only used with `--daily-double-strategy weighted-by-row`
+    #[clap(long, default_value_t = 1.0)]
+    daily_double_bias: f64,
+    /// Fixed `category,answer` cells to use as Daily Doubles, separated by `;`; only used with
+    /// `--daily-double-strategy fixed`
+    #[clap(long, value_delimiter = ';')]
+    daily_double_cells: Vec<String>,
+}
+
+#[derive(Parser, Debug)]
+struct Convert {
+    /// The legacy-format game file to read
+    input: PathBuf,
+    /// Where to write the converted game file
+    #[clap(long, short)]
+    output: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+struct Play {
+    /// The game file to play through
+    game: PathBuf,
 }
 
 fn main() -> ToolResult<()> {
     let cli = CLI::parse();
     match cli {
-        CLI::Show(show) => jeopardytool::show(show.prefix),
-        CLI::Create => todo!("subcommand create"),
-        CLI::Convert => todo!("subcommand convert"),
+        CLI::Show(show) => jeopardytool::show(show.prefix, show.dir),
+        CLI::Create(create) => jeopardytool::create(
+            create.output,
+            create.daily_double_strategy,
+            create.daily_double_bias,
+            create.daily_double_cells,
+        ),
+        CLI::Convert(convert) => jeopardytool::convert(convert.input, convert.output),
+        CLI::Play(play) => jeopardytool::play(play.game),
     }
 }
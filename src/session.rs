@@ -0,0 +1,443 @@
+//! Runtime play: a `GameSession` owns a `JeopardyGame`, the players competing over it, and
+//! which board cells are still open, then drives an interactive play loop for the `play`
+//! subcommand.
+
+use crate::{prompt_string, question_of, JeopardyGame, ToolError, ToolResult};
+use requestty::Question;
+use std::path::PathBuf;
+
+/// Point values awarded for a row, indexed by an answer's position within its category.
+pub(crate) const ROW_VALUES: [i64; 5] = [100, 200, 300, 400, 500];
+
+/// The lowest amount a Daily-Double wager may be set to.
+const MINIMUM_WAGER: i64 = 5;
+
+/// The plain, un-doubled point value for a row, independent of whether it hides a Daily Double.
+fn base_row_value(idx: usize) -> i64 {
+    ROW_VALUES.get(idx).copied().unwrap_or(*ROW_VALUES.last().unwrap())
+}
+
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub name: String,
+    pub score: i64,
+}
+
+impl Player {
+    pub fn new(name: impl Into<String>) -> Self {
+        Player {
+            name: name.into(),
+            score: 0,
+        }
+    }
+}
+
+/// Tracks which `(category, answer)` cells have already been played.
+#[derive(Debug)]
+struct Board {
+    open: Vec<Vec<bool>>,
+}
+
+impl Board {
+    fn new(game: &JeopardyGame) -> Self {
+        Board {
+            open: game
+                .categories
+                .iter()
+                .map(|c| vec![true; c.answers.len()])
+                .collect(),
+        }
+    }
+
+    fn is_open(&self, cat: usize, idx: usize) -> bool {
+        self.open
+            .get(cat)
+            .and_then(|c| c.get(idx))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    fn close(&mut self, cat: usize, idx: usize) {
+        if let Some(cell) = self.open.get_mut(cat).and_then(|c| c.get_mut(idx)) {
+            *cell = false;
+        }
+    }
+
+    fn is_cleared(&self) -> bool {
+        self.open.iter().flatten().all(|open| !open)
+    }
+}
+
+/// The clue currently on the board, waiting to be scored.
+struct RevealedClue {
+    category: usize,
+    index: usize,
+    points: i64,
+}
+
+pub struct GameSession {
+    game: JeopardyGame,
+    players: Vec<Player>,
+    board: Board,
+    current: Option<RevealedClue>,
+}
+
+impl GameSession {
+    pub fn new(game: JeopardyGame, players: Vec<Player>) -> Self {
+        let board = Board::new(&game);
+        GameSession {
+            game,
+            players,
+            board,
+            current: None,
+        }
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn is_board_cleared(&self) -> bool {
+        self.board.is_cleared()
+    }
+
+    /// The Final Jeopardy category and clue text, to be revealed once the board is cleared and
+    /// before wagers are collected.
+    pub fn final_jeopardy_clue(&self) -> (&str, &str) {
+        (
+            self.game.final_jeopardy.category.as_str(),
+            question_of(&self.game.final_jeopardy.clue),
+        )
+    }
+
+    fn points_for(&self, cat: usize, idx: usize) -> i64 {
+        let base = base_row_value(idx);
+        if self.game.categories[cat].answers[idx].is_double_jeopardy() {
+            base * 2
+        } else {
+            base
+        }
+    }
+
+    /// Reveal a clue, returning its question text, point value, and whether it's a Daily
+    /// Double (in which case the point value must be replaced by a call to `wager`).
+    pub fn reveal(&mut self, cat: usize, idx: usize) -> Result<(String, i64, bool), ToolError> {
+        let answer = self
+            .game
+            .categories
+            .get(cat)
+            .and_then(|c| c.answers.get(idx))
+            .ok_or_else(|| ToolError::Other("no such cell on the board".to_string()))?;
+        if !self.board.is_open(cat, idx) {
+            return Err(ToolError::Other(
+                "that cell has already been played".to_string(),
+            ));
+        }
+        let is_daily_double = answer.is_double_jeopardy();
+        let points = self.points_for(cat, idx);
+        self.current = Some(RevealedClue {
+            category: cat,
+            index: idx,
+            points,
+        });
+        Ok((question_of(answer).to_string(), points, is_daily_double))
+    }
+
+    /// Set the wager for the currently revealed Daily Double, bounded between `minimum` and
+    /// the wagering player's current score (or the board's top row value if their score is
+    /// zero or below), mirroring how a starting-token wager is bounded in turn-based card games.
+    pub fn wager(&mut self, player: usize, amount: i64, minimum: i64) -> Result<i64, ToolError> {
+        let score = self
+            .players
+            .get(player)
+            .map(|p| p.score)
+            .ok_or_else(|| ToolError::Other("no such player".to_string()))?;
+        let max_row_value = *ROW_VALUES.last().unwrap();
+        let ceiling = if score > 0 { score } else { max_row_value };
+        let bounded = amount.clamp(minimum, ceiling.max(minimum));
+
+        let clue = self
+            .current
+            .as_mut()
+            .ok_or_else(|| ToolError::Other("no clue is currently revealed".to_string()))?;
+        clue.points = bounded;
+        Ok(bounded)
+    }
+
+    /// Award or deduct points for the currently revealed clue and close its cell.
+    pub fn award(&mut self, player: usize, correct: bool) -> Result<(), ToolError> {
+        let clue = self
+            .current
+            .take()
+            .ok_or_else(|| ToolError::Other("no clue is currently revealed".to_string()))?;
+        let delta = if correct { clue.points } else { -clue.points };
+        let player = self
+            .players
+            .get_mut(player)
+            .ok_or_else(|| ToolError::Other("no such player".to_string()))?;
+        player.score += delta;
+        self.board.close(clue.category, clue.index);
+        Ok(())
+    }
+
+    /// Resolve Final Jeopardy: every player wagers in secret, then wins or loses that wager
+    /// based on whether they answered correctly. Wagers are bounded to a player's own score,
+    /// since a player with nothing can't stake anything.
+    pub fn resolve_final_jeopardy(
+        &mut self,
+        wagers: &[i64],
+        correct: &[bool],
+    ) -> Result<(), ToolError> {
+        if wagers.len() != self.players.len() || correct.len() != self.players.len() {
+            return Err(ToolError::Other(
+                "expected one wager and one result per player".to_string(),
+            ));
+        }
+        for ((player, &wager), &was_correct) in
+            self.players.iter_mut().zip(wagers).zip(correct)
+        {
+            let bounded = wager.clamp(0, player.score.max(0));
+            player.score += if was_correct { bounded } else { -bounded };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FinalJeopardy, JeopardyAnswer, JeopardyCategory};
+
+    fn sample_game() -> JeopardyGame {
+        let categories = (0..5)
+            .map(|cat| JeopardyCategory {
+                name: format!("Category {}", cat),
+                answers: (0..5)
+                    .map(|ans| JeopardyAnswer::Text {
+                        answer: format!("answer {}", ans),
+                        question: format!("question {}", ans),
+                        double_jeopardy: false,
+                    })
+                    .collect(),
+            })
+            .collect();
+        JeopardyGame {
+            categories,
+            final_jeopardy: FinalJeopardy {
+                category: "Final Category".to_string(),
+                clue: JeopardyAnswer::Text {
+                    answer: "final answer".to_string(),
+                    question: "final question".to_string(),
+                    double_jeopardy: false,
+                },
+            },
+            schema_version: 1,
+        }
+    }
+
+    fn sample_session(scores: &[i64]) -> GameSession {
+        let players = scores
+            .iter()
+            .enumerate()
+            .map(|(i, &score)| Player {
+                name: format!("Player {}", i),
+                score,
+            })
+            .collect();
+        GameSession::new(sample_game(), players)
+    }
+
+    #[test]
+    fn wager_clamps_up_to_minimum() {
+        let mut session = sample_session(&[1000]);
+        session.reveal(0, 0).unwrap();
+        let wager = session.wager(0, 1, 5).unwrap();
+        assert_eq!(wager, 5);
+    }
+
+    #[test]
+    fn wager_clamps_down_to_positive_score() {
+        let mut session = sample_session(&[300]);
+        session.reveal(0, 0).unwrap();
+        let wager = session.wager(0, 10_000, 5).unwrap();
+        assert_eq!(wager, 300);
+    }
+
+    #[test]
+    fn wager_clamps_to_board_max_when_score_is_not_positive() {
+        let mut session = sample_session(&[-200]);
+        session.reveal(0, 0).unwrap();
+        let wager = session.wager(0, 10_000, 5).unwrap();
+        assert_eq!(wager, *ROW_VALUES.last().unwrap());
+    }
+
+    #[test]
+    fn award_correct_adds_points_and_closes_the_cell() {
+        let mut session = sample_session(&[0]);
+        let (_, points, _) = session.reveal(0, 2).unwrap();
+        session.award(0, true).unwrap();
+        assert_eq!(session.players()[0].score, points);
+        assert!(!session.board.is_open(0, 2));
+    }
+
+    #[test]
+    fn award_incorrect_subtracts_points_and_closes_the_cell() {
+        let mut session = sample_session(&[0]);
+        let (_, points, _) = session.reveal(0, 2).unwrap();
+        session.award(0, false).unwrap();
+        assert_eq!(session.players()[0].score, -points);
+        assert!(!session.board.is_open(0, 2));
+    }
+
+    #[test]
+    fn resolve_final_jeopardy_clamps_each_players_wager_to_their_own_score() {
+        let mut session = sample_session(&[500, -100]);
+        session
+            .resolve_final_jeopardy(&[10_000, 10_000], &[true, true])
+            .unwrap();
+        // The second player has a non-positive score, so their wager clamps to 0 and they
+        // can't win or lose anything.
+        assert_eq!(session.players()[0].score, 1000);
+        assert_eq!(session.players()[1].score, -100);
+    }
+
+    #[test]
+    fn resolve_final_jeopardy_deducts_on_a_wrong_answer() {
+        let mut session = sample_session(&[500]);
+        session.resolve_final_jeopardy(&[200], &[false]).unwrap();
+        assert_eq!(session.players()[0].score, 300);
+    }
+}
+
+/// Load a game from `path` and walk it through an interactive play session.
+pub fn play(path: PathBuf) -> ToolResult<()> {
+    match load_and_play(path) {
+        Ok(()) => ToolResult::Ok(()),
+        Err(e) => ToolResult::Err(e),
+    }
+}
+
+fn load_and_play(path: PathBuf) -> Result<(), ToolError> {
+    let contents = std::fs::read_to_string(&path).map_err(|e| ToolError::Other(e.to_string()))?;
+    let game: JeopardyGame =
+        serde_json::from_str(&contents).map_err(|e| ToolError::Other(e.to_string()))?;
+
+    let players = prompt_players()?;
+    let mut session = GameSession::new(game, players);
+
+    while !session.is_board_cleared() {
+        let (cat, idx) = prompt_cell(&session)?;
+        let (question, points, is_daily_double) = session.reveal(cat, idx)?;
+
+        let points = if is_daily_double {
+            println!("Daily Double!");
+            let wagering_player = prompt_player_index(&session, "Who found it?")?;
+            let amount = prompt_int("Wager how much?", 0, i64::MAX)?;
+            session.wager(wagering_player, amount, MINIMUM_WAGER)?
+        } else {
+            points
+        };
+        println!("For {} points: {}", points, question);
+
+        let player = prompt_player_index(&session, "Who answered?")?;
+        let correct = prompt_bool("Was the answer correct?")?;
+        session.award(player, correct)?;
+    }
+
+    println!("The board is clear! Time for Final Jeopardy.");
+    let (category, question) = session.final_jeopardy_clue();
+    println!("Category: {}", category);
+    println!("{}", question);
+    let wagers = session
+        .players()
+        .iter()
+        .map(|p| prompt_int(&format!("{}'s secret wager", p.name), 0, p.score.max(0)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let results = session
+        .players()
+        .iter()
+        .map(|p| prompt_bool(&format!("Did {} answer Final Jeopardy correctly?", p.name)))
+        .collect::<Result<Vec<_>, _>>()?;
+    session.resolve_final_jeopardy(&wagers, &results)?;
+
+    println!("Final scores:");
+    for player in session.players() {
+        println!("  {}: {}", player.name, player.score);
+    }
+    Ok(())
+}
+
+fn prompt_players() -> Result<Vec<Player>, ToolError> {
+    let count = prompt_int("How many players?", 1, 10)?;
+    (0..count)
+        .map(|i| prompt_string(&format!("Name of player {}", i + 1)).map(Player::new))
+        .collect()
+}
+
+fn prompt_cell(session: &GameSession) -> Result<(usize, usize), ToolError> {
+    let mut choices = Vec::new();
+    for (cat_idx, category) in session.game.categories.iter().enumerate() {
+        for ans_idx in 0..category.answers.len() {
+            if session.board.is_open(cat_idx, ans_idx) {
+                // Always show the row's plain value here: revealing the doubled value would
+                // give away which cell hides the Daily Double before it's picked.
+                choices.push((
+                    format!("{} - ${}", category.name, base_row_value(ans_idx)),
+                    (cat_idx, ans_idx),
+                ));
+            }
+        }
+    }
+
+    let answer = requestty::prompt_one(
+        Question::select("cell")
+            .message("Pick a clue")
+            .choices(choices.iter().map(|(label, _)| label.clone()).collect::<Vec<_>>())
+            .build(),
+    )
+    .map_err(|e| ToolError::Other(e.to_string()))?;
+    let item = answer
+        .as_list_item()
+        .ok_or_else(|| ToolError::Other("expected a selection".to_string()))?;
+    Ok(choices[item.index].1)
+}
+
+fn prompt_player_index(session: &GameSession, message: &str) -> Result<usize, ToolError> {
+    let answer = requestty::prompt_one(
+        Question::select("player")
+            .message(message)
+            .choices(session.players.iter().map(|p| p.name.clone()).collect::<Vec<_>>())
+            .build(),
+    )
+    .map_err(|e| ToolError::Other(e.to_string()))?;
+    let item = answer
+        .as_list_item()
+        .ok_or_else(|| ToolError::Other("expected a selection".to_string()))?;
+    Ok(item.index)
+}
+
+fn prompt_bool(message: &str) -> Result<bool, ToolError> {
+    requestty::prompt_one(Question::confirm("value").message(message).build())
+        .map_err(|e| ToolError::Other(e.to_string()))?
+        .as_bool()
+        .ok_or_else(|| ToolError::Other("expected yes or no".to_string()))
+}
+
+fn prompt_int(message: &str, min: i64, max: i64) -> Result<i64, ToolError> {
+    let answer = requestty::prompt_one(
+        Question::int("value")
+            .message(message)
+            .validate(move |n, _| {
+                if (min..=max).contains(&n) {
+                    Ok(())
+                } else {
+                    Err(format!("enter a number between {} and {}", min, max))
+                }
+            })
+            .build(),
+    )
+    .map_err(|e| ToolError::Other(e.to_string()))?;
+    answer
+        .as_int()
+        .ok_or_else(|| ToolError::Other("expected a number".to_string()))
+}